@@ -31,6 +31,8 @@
 
 use lazy_static::lazy_static;
 use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use unicode_normalization::UnicodeNormalization;
 
 // Rustfmt really wants to put some of my comments at the end of
 // lines.
@@ -95,6 +97,13 @@ const SCRIPT_AND_STYLE: &str = r"(<script[^>]*?>.*?</script>|<style[^>]*?>.*?</s
 /// limit the length of a slug, remove stopwords or just "a|an|the"
 /// language articles, or other modifications.
 pub fn sanitize_and_split(title: &str) -> Vec<String> {
+    sanitize_and_split_cased(title, true)
+}
+
+/// Does the actual work of [`sanitize_and_split`], but allows the
+/// caller to skip the lowercasing step so that [`slugify_with`] can
+/// honor [`SlugifyOptions::lowercase`].
+fn sanitize_and_split_cased(title: &str, lowercase: bool) -> Vec<String> {
     #[rustfmt::skip]
     extra_lazy! {
         STRIP_DANGEROUS_TAGS, SCRIPT_AND_STYLE,
@@ -106,7 +115,11 @@ pub fn sanitize_and_split(title: &str) -> Vec<String> {
         REMOVE_REMAINING_PUNCT, r"[^%\p{Alphabetic}0-9 -]+",
     }
 
-    let workspace = title.to_string().to_lowercase();
+    let workspace = if lowercase {
+        title.to_string().to_lowercase()
+    } else {
+        title.to_string()
+    };
 
     #[rustfmt::skip]
     mk_workspace!(
@@ -130,7 +143,333 @@ pub fn sanitize_and_split(title: &str) -> Vec<String> {
 /// Sanitize a string and return the string lowercased with a single
 /// hyphen between the words.
 pub fn slugify(title: &str) -> String {
-    sanitize_and_split(title).join("-")
+    slugify_with(title, &SlugifyOptions::default())
+}
+
+/// Options controlling how [`slugify_with`] assembles a slug from the
+/// atomized word vector produced by [`sanitize_and_split`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlugifyOptions {
+    /// The string used to join the atomized words together. Defaults
+    /// to `"-"`.
+    pub separator: String,
+    /// Whether to lowercase the atomized words before joining them.
+    /// Defaults to `true`.
+    pub lowercase: bool,
+    /// An optional maximum length, in characters, for the resulting
+    /// slug. When set, whole words are dropped from the end of the
+    /// slug until it fits within this length; no word is ever
+    /// truncated mid-word.
+    pub max_length: Option<usize>,
+}
+
+impl Default for SlugifyOptions {
+    fn default() -> Self {
+        SlugifyOptions {
+            separator: "-".to_string(),
+            lowercase: true,
+            max_length: None,
+        }
+    }
+}
+
+/// Sanitize a string and join the atomized words into a slug using
+/// the given [`SlugifyOptions`], allowing callers to choose a
+/// separator other than `-`, preserve case, or cap the result at a
+/// maximum length without breaking a word in half.
+///
+/// ```
+/// # use wpslugify::{slugify_with, SlugifyOptions};
+/// let opts = SlugifyOptions { separator: "_".to_string(), ..Default::default() };
+/// assert_eq!(slugify_with("Boys & Girls & Those Elsewhere", &opts), "boys_girls_those_elsewhere");
+/// ```
+pub fn slugify_with(title: &str, opts: &SlugifyOptions) -> String {
+    let words = sanitize_and_split_cased(title, opts.lowercase);
+
+    let words = match opts.max_length {
+        Some(max_length) => truncate_words(words, &opts.separator, max_length),
+        None => words,
+    };
+
+    words.join(&opts.separator)
+}
+
+/// Drop whole words from the end of `words` until joining them with
+/// `separator` would fit within `max_length` characters.
+fn truncate_words(words: Vec<String>, separator: &str, max_length: usize) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut len = 0;
+    let separator_len = separator.chars().count();
+
+    for word in words {
+        let word_len = word.chars().count();
+        let additional = if result.is_empty() {
+            word_len
+        } else {
+            word_len + separator_len
+        };
+
+        if len + additional > max_length {
+            break;
+        }
+
+        len += additional;
+        result.push(word);
+    }
+
+    result
+}
+
+/// Output mode for [`slugify_mode`], controlling how non-ASCII
+/// Unicode letters are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlugMode {
+    /// Today's behavior: leaves UTF-8 within the Unicode
+    /// `{Alphabetic}` properties class intact.
+    Default,
+    /// Transliterates accented Latin letters to their closest ASCII
+    /// equivalent, then strips any remaining non-ASCII codepoints,
+    /// producing a pure-ASCII slug.
+    Ascii,
+    /// Transliterates accented Latin letters to their closest ASCII
+    /// equivalent (e.g. `ö` -> `o`, `ß` -> `ss`). Combining marks are
+    /// removed from letters of every script, not only Latin ones (e.g.
+    /// `й` -> `и`); other non-ASCII Unicode alphabetic characters are
+    /// kept, in NFD-decomposed form.
+    Latin,
+}
+
+// A handful of Latin letters that don't decompose under NFD, so the
+// canonical-combining-class filter in `transliterate_latin` can't
+// reach them; they're rewritten by hand.
+const LATIN_NON_DECOMPOSING: [(char, &str); 5] = [
+    ('ø', "o"),
+    ('ß', "ss"),
+    ('æ', "ae"),
+    ('đ', "d"),
+    ('ł', "l"),
+];
+
+/// Transliterate accented Latin letters down to ASCII: lowercase (so
+/// [`LATIN_NON_DECOMPOSING`], which only lists lowercase forms,
+/// catches uppercase letters too — the result is lowercased again
+/// regardless by [`sanitize_and_split`]), normalize to NFD, drop
+/// combining marks left behind by the decomposition (e.g. `ö` -> `o`),
+/// and rewrite the few letters that don't decompose via
+/// [`LATIN_NON_DECOMPOSING`].
+fn transliterate_latin(title: &str) -> String {
+    let mut workspace = title.to_lowercase();
+    for (from, to) in LATIN_NON_DECOMPOSING.iter() {
+        workspace = workspace.replace(*from, to);
+    }
+
+    workspace
+        .nfd()
+        .filter(|c| unicode_normalization::char::canonical_combining_class(*c) == 0)
+        .collect()
+}
+
+/// Sanitize a string into a slug, applying `mode` to control how
+/// non-ASCII Unicode letters are handled.
+///
+/// ```
+/// # use wpslugify::{slugify_mode, SlugMode};
+/// assert_eq!(slugify_mode("Töxic Tësticle Färm?", SlugMode::Default), "töxic-tësticle-färm");
+/// assert_eq!(slugify_mode("Töxic Tësticle Färm?", SlugMode::Latin), "toxic-testicle-farm");
+/// assert_eq!(slugify_mode("Töxic Tësticle Färm?", SlugMode::Ascii), "toxic-testicle-farm");
+/// ```
+pub fn slugify_mode(title: &str, mode: SlugMode) -> String {
+    let transliterated;
+    let title = match mode {
+        SlugMode::Default => title,
+        SlugMode::Latin | SlugMode::Ascii => {
+            transliterated = transliterate_latin(title);
+            &transliterated
+        }
+    };
+
+    let words = sanitize_and_split(title);
+
+    let words: Vec<String> = if mode == SlugMode::Ascii {
+        words
+            .into_iter()
+            .map(|word| word.chars().filter(char::is_ascii).collect::<String>())
+            .filter(|word| !word.is_empty())
+            .collect()
+    } else {
+        words
+    };
+
+    words.join("-")
+}
+
+/// A strategy for [`slugify_strategy`], for when callers want
+/// filesystem- or path-safe output rather than a full WordPress-style
+/// slug.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlugStrategy {
+    /// The current full WordPress-style slugification, as produced by
+    /// [`slugify`].
+    On,
+    /// The minimum needed to make a string safe to use as a
+    /// filename or URL path segment: trailing spaces and `.` are
+    /// trimmed, and the NTFS/URL-forbidden characters
+    /// `<>:"/\|?*` are removed. Case, internal spaces, and all other
+    /// characters are left untouched.
+    Safe,
+    /// Returns the input unchanged.
+    Off,
+}
+
+// The NTFS/URL characters forbidden in a filename or path segment.
+const FILESYSTEM_FORBIDDEN: [char; 9] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+/// Produce a filesystem/path-oriented slug from `s` according to
+/// `strategy`, ranging from the full [`slugify`] transform down to
+/// passing the string through unchanged.
+///
+/// ```
+/// # use wpslugify::{slugify_strategy, SlugStrategy};
+/// assert_eq!(slugify_strategy("My Report: Final.", SlugStrategy::Safe), "My Report Final");
+/// assert_eq!(slugify_strategy("My Report: Final.", SlugStrategy::Off), "My Report: Final.");
+/// ```
+pub fn slugify_strategy(s: &str, strategy: SlugStrategy) -> String {
+    match strategy {
+        SlugStrategy::On => slugify(s),
+        SlugStrategy::Off => s.to_string(),
+        SlugStrategy::Safe => s
+            .trim_end_matches([' ', '.'])
+            .chars()
+            .filter(|c| !FILESYSTEM_FORBIDDEN.contains(c))
+            .collect(),
+    }
+}
+
+/// Generates unique slugs across a series of titles, appending a
+/// numeric suffix when a slug has already been emitted. Useful for
+/// slugifying a whole list of titles at once, e.g. table-of-contents
+/// entries or post headings, where collisions must be disambiguated
+/// rather than silently overwriting one another.
+///
+/// ```
+/// # use wpslugify::SlugDeduper;
+/// let mut deduper = SlugDeduper::new();
+/// assert_eq!(deduper.unique_slugify("My Title"), "my-title");
+/// assert_eq!(deduper.unique_slugify("My Title"), "my-title-1");
+/// assert_eq!(deduper.unique_slugify("My Title"), "my-title-2");
+/// ```
+#[derive(Debug, Default)]
+pub struct SlugDeduper {
+    seen: HashMap<String, usize>,
+}
+
+impl SlugDeduper {
+    /// Create a new, empty deduper.
+    pub fn new() -> Self {
+        SlugDeduper {
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Slugify `title`, returning the bare slug the first time it is
+    /// seen, and `<slug>-<n>` for each subsequent collision. Every
+    /// slug this returns is registered, so a later title that happens
+    /// to naturally slugify to an already-emitted `<slug>-<n>` keeps
+    /// counting from there rather than colliding with it.
+    pub fn unique_slugify(&mut self, title: &str) -> String {
+        let base = slugify(title);
+
+        if !self.seen.contains_key(&base) {
+            self.seen.insert(base.clone(), 0);
+            return base;
+        }
+
+        let mut n = self.seen[&base];
+        loop {
+            n += 1;
+            let candidate = format!("{}-{}", base, n);
+            if !self.seen.contains_key(&candidate) {
+                self.seen.insert(base, n);
+                self.seen.insert(candidate.clone(), 0);
+                return candidate;
+            }
+        }
+    }
+}
+
+/// A set of words to strip from an atomized word vector via
+/// [`strip_words`]. See [`ENGLISH_ARTICLES`] and [`ENGLISH_STOPWORDS`]
+/// for the built-in English sets.
+#[derive(Debug, Clone)]
+pub struct StopwordSet(HashSet<&'static str>);
+
+impl StopwordSet {
+    /// Build a stopword set out of a static list of words.
+    pub fn new(words: &[&'static str]) -> Self {
+        StopwordSet(words.iter().copied().collect())
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.0.contains(word)
+    }
+}
+
+const ENGLISH_ARTICLE_WORDS: [&str; 3] = ["a", "an", "the"];
+
+#[rustfmt::skip]
+const ENGLISH_STOPWORD_WORDS: [&str; 44] = [
+    "a", "an", "the",
+    "and", "or", "but", "nor",
+    "in", "on", "at", "to", "for", "of", "with", "by", "as", "from", "into", "about",
+    "is", "are", "was", "were", "be", "been", "being",
+    "this", "that", "these", "those", "it", "its",
+    "than", "then", "so", "such", "no", "not", "only", "own", "too", "very",
+    "i", "you",
+];
+
+lazy_static! {
+    /// The English `a|an|the` articles, for use with [`strip_words`].
+    pub static ref ENGLISH_ARTICLES: StopwordSet = StopwordSet::new(&ENGLISH_ARTICLE_WORDS);
+
+    /// A broader set of common English stopwords (articles,
+    /// conjunctions, prepositions, and other filler words), for use
+    /// with [`strip_words`].
+    pub static ref ENGLISH_STOPWORDS: StopwordSet = StopwordSet::new(&ENGLISH_STOPWORD_WORDS);
+}
+
+/// Remove any word in `set` from `words`, as a post-sanitize filter
+/// over the atomized word vector returned by [`sanitize_and_split`].
+/// Never strips a word if doing so would leave the result empty; in
+/// that case the unfiltered `words` are returned instead.
+///
+/// ```
+/// # use wpslugify::{sanitize_and_split, strip_words, ENGLISH_STOPWORDS};
+/// let words = sanitize_and_split("Boys & Girls & Those Elsewhere");
+/// assert_eq!(strip_words(words, &ENGLISH_STOPWORDS).join("-"), "boys-girls-elsewhere");
+/// ```
+pub fn strip_words(words: Vec<String>, set: &StopwordSet) -> Vec<String> {
+    let filtered: Vec<String> = words
+        .iter()
+        .filter(|word| !set.contains(word))
+        .cloned()
+        .collect();
+
+    if filtered.is_empty() {
+        words
+    } else {
+        filtered
+    }
+}
+
+/// Sanitize a string into a slug with [`ENGLISH_STOPWORDS`] stripped
+/// out, for compact SEO-friendly slugs.
+///
+/// ```
+/// # use wpslugify::slugify_without_stopwords;
+/// assert_eq!(slugify_without_stopwords("Boys & Girls & Those Elsewhere"), "boys-girls-elsewhere");
+/// ```
+pub fn slugify_without_stopwords(title: &str) -> String {
+    strip_words(sanitize_and_split(title), &ENGLISH_STOPWORDS).join("-")
 }
 
 #[cfg(test)]
@@ -156,4 +495,154 @@ mod tests {
             assert_eq!(slugify(sample.0), sample.1);
         }
     }
+
+    #[test]
+    fn custom_separator() {
+        let opts = SlugifyOptions {
+            separator: "_".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            slugify_with("Boys & Girls & Those Elsewhere", &opts),
+            "boys_girls_those_elsewhere"
+        );
+    }
+
+    #[test]
+    fn preserves_case() {
+        let opts = SlugifyOptions {
+            lowercase: false,
+            ..Default::default()
+        };
+        assert_eq!(slugify_with("This is a test.", &opts), "This-is-a-test");
+    }
+
+    #[test]
+    fn truncates_on_word_boundary() {
+        let opts = SlugifyOptions {
+            max_length: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(
+            slugify_with("Boys & Girls & Those Elsewhere", &opts),
+            "boys-girls"
+        );
+    }
+
+    #[test]
+    fn max_length_counts_characters_not_bytes() {
+        // "töxic" is 5 characters but 6 bytes (ö is 2 bytes in UTF-8).
+        let opts = SlugifyOptions {
+            max_length: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(slugify_with("Töxic Tësticle Färm?", &opts), "töxic");
+    }
+
+    #[test]
+    fn mode_default_keeps_unicode() {
+        assert_eq!(
+            slugify_mode("Töxic Tësticle Färm?", SlugMode::Default),
+            "töxic-tësticle-färm"
+        );
+    }
+
+    #[test]
+    fn mode_latin_transliterates_accents() {
+        assert_eq!(
+            slugify_mode("Töxic Tësticle Färm?", SlugMode::Latin),
+            "toxic-testicle-farm"
+        );
+        assert_eq!(slugify_mode("Øresund", SlugMode::Latin), "oresund");
+        assert_eq!(slugify_mode("Straße", SlugMode::Latin), "strasse");
+    }
+
+    #[test]
+    fn mode_latin_transliterates_uppercase_non_decomposing_letters() {
+        assert_eq!(slugify_mode("Æon", SlugMode::Latin), "aeon");
+        assert_eq!(slugify_mode("Łódź", SlugMode::Latin), "lodz");
+    }
+
+    #[test]
+    fn mode_ascii_strips_remaining_unicode() {
+        assert_eq!(
+            slugify_mode("日本語 Töxic", SlugMode::Ascii),
+            "toxic"
+        );
+    }
+
+    #[test]
+    fn strategy_on_matches_slugify() {
+        assert_eq!(
+            slugify_strategy("This is a test.", SlugStrategy::On),
+            slugify("This is a test.")
+        );
+    }
+
+    #[test]
+    fn strategy_safe_strips_forbidden_chars_and_trailing_dot() {
+        assert_eq!(
+            slugify_strategy("My Report: Final.", SlugStrategy::Safe),
+            "My Report Final"
+        );
+        assert_eq!(
+            slugify_strategy(r#"a<b>c:d"e/f\g|h?i*j  "#, SlugStrategy::Safe),
+            "abcdefghij"
+        );
+    }
+
+    #[test]
+    fn strategy_off_is_unchanged() {
+        assert_eq!(
+            slugify_strategy("My Report: Final.", SlugStrategy::Off),
+            "My Report: Final."
+        );
+    }
+
+    #[test]
+    fn deduper_appends_incrementing_suffix_on_collision() {
+        let mut deduper = SlugDeduper::new();
+        assert_eq!(deduper.unique_slugify("My Title"), "my-title");
+        assert_eq!(deduper.unique_slugify("My Title"), "my-title-1");
+        assert_eq!(deduper.unique_slugify("My Title"), "my-title-2");
+    }
+
+    #[test]
+    fn deduper_tracks_distinct_base_slugs_independently() {
+        let mut deduper = SlugDeduper::new();
+        assert_eq!(deduper.unique_slugify("Alpha"), "alpha");
+        assert_eq!(deduper.unique_slugify("Beta"), "beta");
+        assert_eq!(deduper.unique_slugify("Alpha"), "alpha-1");
+    }
+
+    #[test]
+    fn deduper_suffixes_a_natural_slug_that_collides_with_a_generated_one() {
+        let mut deduper = SlugDeduper::new();
+        assert_eq!(deduper.unique_slugify("My Title"), "my-title");
+        assert_eq!(deduper.unique_slugify("My Title"), "my-title-1");
+        assert_eq!(deduper.unique_slugify("My Title 1"), "my-title-1-1");
+    }
+
+    #[test]
+    fn strips_stopwords() {
+        assert_eq!(
+            slugify_without_stopwords("Boys & Girls & Those Elsewhere"),
+            "boys-girls-elsewhere"
+        );
+    }
+
+    #[test]
+    fn strip_words_falls_back_when_result_would_be_empty() {
+        let words = sanitize_and_split("The a an");
+        assert_eq!(strip_words(words.clone(), &ENGLISH_ARTICLES), words);
+    }
+
+    #[test]
+    fn strip_words_with_article_set() {
+        let words = sanitize_and_split("The Quick Fox");
+        assert_eq!(
+            strip_words(words, &ENGLISH_ARTICLES).join("-"),
+            "quick-fox"
+        );
+    }
 }